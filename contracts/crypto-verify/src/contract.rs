@@ -1,11 +1,13 @@
 use sha2::{Digest, Sha256};
 
 use cosmwasm_std::{
-    entry_point, to_binary, Deps, DepsMut, Env, MessageInfo, QueryResponse, Response, StdResult,
+    entry_point, to_binary, Deps, DepsMut, Env, MessageInfo, QueryResponse, Response, StdError,
+    StdResult,
 };
 
 use crate::msg::{
-    list_verifications, HandleMsg, InitMsg, ListVerificationsResponse, QueryMsg, VerifyResponse,
+    list_verifications, HandleMsg, InitMsg, ListVerificationsResponse, QueryMsg,
+    RecoverPubkeyResponse, VerifyResponse,
 };
 
 pub const VERSION: &str = "crypto-verify-v1";
@@ -38,6 +40,36 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<QueryResponse> {
             &signature.0,
             &public_key.0,
         )?),
+        QueryMsg::RecoverPubkey {
+            message_hash,
+            signature,
+            recovery_param,
+        } => to_binary(&query_recover_pubkey(
+            deps,
+            &message_hash.0,
+            &signature.0,
+            recovery_param,
+        )?),
+        QueryMsg::VerifyEd25519Signature {
+            message,
+            signature,
+            public_key,
+        } => to_binary(&query_verify_ed25519(
+            deps,
+            &message.0,
+            &signature.0,
+            &public_key.0,
+        )?),
+        QueryMsg::VerifyEd25519Batch {
+            messages,
+            signatures,
+            public_keys,
+        } => to_binary(&query_verify_ed25519_batch(
+            deps,
+            messages.into_iter().map(|m| m.0).collect(),
+            signatures.into_iter().map(|s| s.0).collect(),
+            public_keys.into_iter().map(|k| k.0).collect(),
+        )?),
         QueryMsg::ListVerificationSchemes {} => to_binary(&query_list_verifications(deps)?),
     }
 }
@@ -57,6 +89,48 @@ pub fn query_verify(
     Ok(VerifyResponse { verifies })
 }
 
+pub fn query_recover_pubkey(
+    deps: Deps,
+    message_hash: &[u8],
+    signature: &[u8],
+    recovery_param: u8,
+) -> StdResult<RecoverPubkeyResponse> {
+    let pubkey = deps
+        .api
+        .secp256k1_recover_pubkey(message_hash, signature, recovery_param)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+    Ok(RecoverPubkeyResponse {
+        pubkey: pubkey.into(),
+    })
+}
+
+pub fn query_verify_ed25519(
+    deps: Deps,
+    message: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+) -> StdResult<VerifyResponse> {
+    // ed25519 signs the message itself, not a pre-hash of it.
+    let verifies = deps.api.ed25519_verify(message, signature, public_key);
+    Ok(VerifyResponse { verifies })
+}
+
+pub fn query_verify_ed25519_batch(
+    deps: Deps,
+    messages: Vec<Vec<u8>>,
+    signatures: Vec<Vec<u8>>,
+    public_keys: Vec<Vec<u8>>,
+) -> StdResult<VerifyResponse> {
+    let messages: Vec<_> = messages.iter().map(|m| m.as_slice()).collect();
+    let signatures: Vec<_> = signatures.iter().map(|s| s.as_slice()).collect();
+    let public_keys: Vec<_> = public_keys.iter().map(|k| k.as_slice()).collect();
+
+    let verifies = deps
+        .api
+        .ed25519_batch_verify(&messages, &signatures, &public_keys);
+    Ok(VerifyResponse { verifies })
+}
+
 pub fn query_list_verifications(deps: Deps) -> StdResult<ListVerificationsResponse> {
     let verification_schemes: Vec<_> = list_verifications(deps)?;
     Ok(ListVerificationsResponse {
@@ -78,6 +152,23 @@ mod tests {
     const SIGNATURE_HEX: &str = "207082eb2c3dfa0b454e0906051270ba4074ac93760ba9e7110cd9471475111151eb0dbbc9920e72146fb564f99d039802bf6ef2561446eb126ef364d21ee9c4";
     const PUBLIC_KEY_HEX: &str = "04051c1ee2190ecfb174bfe4f90763f2b4ff7517b70a2aec1876ebcfd644c4633fb03f3cfbd94b1f376e34592d9d41ccaf640bb751b00a1fadeb0c01157769eb73";
 
+    const MESSAGE_HASH_HEX: &str =
+        "6666229ef330ea2b79f4d78138c2f1252e5b11758d74184646cba12b3f9cfb3a";
+    const RECOVER_SIGNATURE_HEX: &str = "5ad2703f5b4f4b9dea4c28fa30d86d3781d28e09dd51aae1208de80bb6155bee6f15028626e59840ad6d08e1dab3177b005bf41e88cf50fccfb058f46281e3dc";
+    const RECOVERED_PUBLIC_KEY_HEX: &str = "04f01d6b9018ab421dd410404cb869072065522bf85734008f105cf385a023a80f0eba29d0f0c5408ed681984dc525982abefccd9f7ff01dd26da4999cf3f6a295";
+
+    // Test 1 from RFC 8032 section 7.1 (empty message).
+    const ED25519_MESSAGE_HEX: &str = "";
+    const ED25519_SIGNATURE_HEX: &str = "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+    const ED25519_PUBLIC_KEY_HEX: &str =
+        "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+
+    // Test 2 from RFC 8032 section 7.1 (1-byte message).
+    const ED25519_MESSAGE2_HEX: &str = "72";
+    const ED25519_SIGNATURE2_HEX: &str = "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00";
+    const ED25519_PUBLIC_KEY2_HEX: &str =
+        "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c";
+
     fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
         let mut deps = mock_dependencies(&[]);
         let msg = InitMsg {};
@@ -163,8 +254,157 @@ mod tests {
         assert_eq!(
             res,
             ListVerificationsResponse {
-                verification_schemes: vec!["secp256k1".into()]
+                verification_schemes: vec![
+                    "secp256k1".into(),
+                    "secp256k1_recover_pubkey".into(),
+                    "ed25519".into(),
+                    "ed25519_batch".into(),
+                ]
             }
         );
     }
+
+    #[test]
+    fn recover_pubkey_works() {
+        let deps = setup();
+
+        let message_hash = hex::decode(MESSAGE_HASH_HEX).unwrap();
+        let signature = hex::decode(RECOVER_SIGNATURE_HEX).unwrap();
+
+        let recover_msg = QueryMsg::RecoverPubkey {
+            message_hash: Binary(message_hash),
+            signature: Binary(signature),
+            recovery_param: 0,
+        };
+
+        let raw = query(deps.as_ref(), mock_env(), recover_msg).unwrap();
+        let res: RecoverPubkeyResponse = from_slice(&raw).unwrap();
+
+        assert_eq!(res.pubkey.0, hex::decode(RECOVERED_PUBLIC_KEY_HEX).unwrap());
+    }
+
+    #[test]
+    fn recover_pubkey_fails_for_invalid_recovery_param() {
+        let deps = setup();
+
+        let message_hash = hex::decode(MESSAGE_HASH_HEX).unwrap();
+        let signature = hex::decode(RECOVER_SIGNATURE_HEX).unwrap();
+
+        let recover_msg = QueryMsg::RecoverPubkey {
+            message_hash: Binary(message_hash),
+            signature: Binary(signature),
+            recovery_param: 4,
+        };
+
+        let err = query(deps.as_ref(), mock_env(), recover_msg).unwrap_err();
+        assert!(err.to_string().contains("Invalid recovery param"));
+    }
+
+    #[test]
+    fn verify_ed25519_works() {
+        let deps = setup();
+
+        let message = hex::decode(ED25519_MESSAGE_HEX).unwrap();
+        let signature = hex::decode(ED25519_SIGNATURE_HEX).unwrap();
+        let public_key = hex::decode(ED25519_PUBLIC_KEY_HEX).unwrap();
+
+        let verify_msg = QueryMsg::VerifyEd25519Signature {
+            message: Binary(message),
+            signature: Binary(signature),
+            public_key: Binary(public_key),
+        };
+
+        let raw = query(deps.as_ref(), mock_env(), verify_msg).unwrap();
+        let res: VerifyResponse = from_slice(&raw).unwrap();
+
+        assert_eq!(res, VerifyResponse { verifies: true });
+    }
+
+    #[test]
+    fn verify_ed25519_fails() {
+        let deps = setup();
+
+        // not the empty message the signature was created for
+        let message = vec![0x01];
+        let signature = hex::decode(ED25519_SIGNATURE_HEX).unwrap();
+        let public_key = hex::decode(ED25519_PUBLIC_KEY_HEX).unwrap();
+
+        let verify_msg = QueryMsg::VerifyEd25519Signature {
+            message: Binary(message),
+            signature: Binary(signature),
+            public_key: Binary(public_key),
+        };
+
+        let raw = query(deps.as_ref(), mock_env(), verify_msg).unwrap();
+        let res: VerifyResponse = from_slice(&raw).unwrap();
+
+        assert_eq!(res, VerifyResponse { verifies: false });
+    }
+
+    #[test]
+    #[should_panic(expected = "empty")]
+    fn verify_ed25519_panics_for_empty_public_key() {
+        let deps = setup();
+
+        let message = hex::decode(ED25519_MESSAGE_HEX).unwrap();
+        let signature = hex::decode(ED25519_SIGNATURE_HEX).unwrap();
+        let public_key = vec![];
+
+        let verify_msg = QueryMsg::VerifyEd25519Signature {
+            message: Binary(message),
+            signature: Binary(signature),
+            public_key: Binary(public_key),
+        };
+        query(deps.as_ref(), mock_env(), verify_msg).unwrap();
+    }
+
+    #[test]
+    fn verify_ed25519_batch_works() {
+        let deps = setup();
+
+        let verify_msg = QueryMsg::VerifyEd25519Batch {
+            messages: vec![
+                Binary(hex::decode(ED25519_MESSAGE_HEX).unwrap()),
+                Binary(hex::decode(ED25519_MESSAGE2_HEX).unwrap()),
+            ],
+            signatures: vec![
+                Binary(hex::decode(ED25519_SIGNATURE_HEX).unwrap()),
+                Binary(hex::decode(ED25519_SIGNATURE2_HEX).unwrap()),
+            ],
+            public_keys: vec![
+                Binary(hex::decode(ED25519_PUBLIC_KEY_HEX).unwrap()),
+                Binary(hex::decode(ED25519_PUBLIC_KEY2_HEX).unwrap()),
+            ],
+        };
+
+        let raw = query(deps.as_ref(), mock_env(), verify_msg).unwrap();
+        let res: VerifyResponse = from_slice(&raw).unwrap();
+
+        assert_eq!(res, VerifyResponse { verifies: true });
+    }
+
+    #[test]
+    fn verify_ed25519_batch_fails_on_single_bad_signature() {
+        let deps = setup();
+
+        let verify_msg = QueryMsg::VerifyEd25519Batch {
+            messages: vec![
+                Binary(hex::decode(ED25519_MESSAGE_HEX).unwrap()),
+                Binary(vec![0xff]),
+            ],
+            signatures: vec![
+                Binary(hex::decode(ED25519_SIGNATURE_HEX).unwrap()),
+                Binary(hex::decode(ED25519_SIGNATURE2_HEX).unwrap()),
+            ],
+            public_keys: vec![
+                Binary(hex::decode(ED25519_PUBLIC_KEY_HEX).unwrap()),
+                Binary(hex::decode(ED25519_PUBLIC_KEY2_HEX).unwrap()),
+            ],
+        };
+
+        let raw = query(deps.as_ref(), mock_env(), verify_msg).unwrap();
+        let res: VerifyResponse = from_slice(&raw).unwrap();
+
+        assert_eq!(res, VerifyResponse { verifies: false });
+    }
 }
\ No newline at end of file
@@ -0,0 +1,67 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cosmwasm_std::{Binary, Deps, StdResult};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InitMsg {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HandleMsg {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    VerifySignature {
+        message: Binary,
+        signature: Binary,
+        public_key: Binary,
+    },
+    /// Recovers a public key from a secp256k1 signature and message hash, Ethereum
+    /// `ecrecover`-style. `recovery_param` must be in `0..=3`.
+    RecoverPubkey {
+        message_hash: Binary,
+        signature: Binary,
+        recovery_param: u8,
+    },
+    /// Verifies an ed25519 signature over the full message (no pre-hashing).
+    VerifyEd25519Signature {
+        message: Binary,
+        signature: Binary,
+        public_key: Binary,
+    },
+    /// Verifies a batch of ed25519 signatures at once. A single public key or
+    /// message can be repeated for all other entries by passing a slice of length 1.
+    VerifyEd25519Batch {
+        messages: Vec<Binary>,
+        signatures: Vec<Binary>,
+        public_keys: Vec<Binary>,
+    },
+    ListVerificationSchemes {},
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct VerifyResponse {
+    pub verifies: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct RecoverPubkeyResponse {
+    pub pubkey: Binary,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct ListVerificationsResponse {
+    pub verification_schemes: Vec<String>,
+}
+
+pub fn list_verifications(_deps: Deps) -> StdResult<Vec<String>> {
+    Ok(vec![
+        "secp256k1".into(),
+        "secp256k1_recover_pubkey".into(),
+        "ed25519".into(),
+        "ed25519_batch".into(),
+    ])
+}
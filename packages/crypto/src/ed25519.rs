@@ -0,0 +1,114 @@
+use ed25519_dalek::{PublicKey, Signature, Verifier};
+
+use crate::error::{CryptoError, CryptoResult};
+
+/// Verifies an ed25519 signature over the full message (ed25519 signs the
+/// message itself, not a pre-hash of it).
+pub fn ed25519_verify(message: &[u8], signature: &[u8], public_key: &[u8]) -> CryptoResult<bool> {
+    let signature =
+        Signature::from_bytes(signature).map_err(|_| CryptoError::invalid_signature_format())?;
+    let public_key =
+        PublicKey::from_bytes(public_key).map_err(|_| CryptoError::invalid_pubkey_format())?;
+
+    Ok(public_key.verify(message, &signature).is_ok())
+}
+
+/// Verifies a batch of ed25519 signatures at once. A single failure fails
+/// the whole batch. `messages`/`public_keys` of length 1 are broadcast to
+/// match the length of the other slices (e.g. many messages against one
+/// public key, or one message against many keys).
+pub fn ed25519_batch_verify(
+    messages: &[&[u8]],
+    signatures: &[&[u8]],
+    public_keys: &[&[u8]],
+) -> CryptoResult<bool> {
+    let len = signatures.len();
+    if len == 0 {
+        return Ok(true);
+    }
+
+    let messages = broadcast(messages, len)?;
+    let public_keys = broadcast(public_keys, len)?;
+
+    let signatures: Vec<Signature> = signatures
+        .iter()
+        .map(|s| Signature::from_bytes(s).map_err(|_| CryptoError::invalid_signature_format()))
+        .collect::<CryptoResult<_>>()?;
+    let public_keys: Vec<PublicKey> = public_keys
+        .iter()
+        .map(|k| PublicKey::from_bytes(k).map_err(|_| CryptoError::invalid_pubkey_format()))
+        .collect::<CryptoResult<_>>()?;
+
+    match ed25519_dalek::verify_batch(&messages, &signatures, &public_keys) {
+        Ok(()) => Ok(true),
+        Err(_) => Ok(false),
+    }
+}
+
+/// Repeats a length-1 slice `len` times; otherwise requires the slice to
+/// already be exactly `len` long.
+fn broadcast<'a>(items: &[&'a [u8]], len: usize) -> CryptoResult<Vec<&'a [u8]>> {
+    match items.len() {
+        1 => Ok(vec![items[0]; len]),
+        n if n == len => Ok(items.to_vec()),
+        _ => Err(CryptoError::batch_err(
+            "messages, signatures and public_keys must have the same length, or length 1 to be broadcast",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    // RFC 8032 section 7.1, test 1 (empty message).
+    const MESSAGE1: [u8; 0] = [];
+    const SIGNATURE1: [u8; 64] = hex!("e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b");
+    const PUBLIC_KEY1: [u8; 32] =
+        hex!("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a");
+
+    // RFC 8032 section 7.1, test 2 (1-byte message).
+    const MESSAGE2: [u8; 1] = hex!("72");
+    const SIGNATURE2: [u8; 64] = hex!("92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00");
+    const PUBLIC_KEY2: [u8; 32] =
+        hex!("3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c");
+
+    #[test]
+    fn verify_works() {
+        assert!(ed25519_verify(&MESSAGE1, &SIGNATURE1, &PUBLIC_KEY1).unwrap());
+    }
+
+    #[test]
+    fn verify_fails_for_wrong_message() {
+        assert!(!ed25519_verify(&[0xff], &SIGNATURE1, &PUBLIC_KEY1).unwrap());
+    }
+
+    #[test]
+    fn batch_verify_works() {
+        let messages: Vec<&[u8]> = vec![&MESSAGE1, &MESSAGE2];
+        let signatures: Vec<&[u8]> = vec![&SIGNATURE1, &SIGNATURE2];
+        let public_keys: Vec<&[u8]> = vec![&PUBLIC_KEY1, &PUBLIC_KEY2];
+
+        assert!(ed25519_batch_verify(&messages, &signatures, &public_keys).unwrap());
+    }
+
+    #[test]
+    fn batch_verify_broadcasts_single_public_key() {
+        // Same key/message signed twice, checked against a broadcast single key.
+        let messages: Vec<&[u8]> = vec![&MESSAGE1, &MESSAGE1];
+        let signatures: Vec<&[u8]> = vec![&SIGNATURE1, &SIGNATURE1];
+        let public_keys: Vec<&[u8]> = vec![&PUBLIC_KEY1];
+
+        assert!(ed25519_batch_verify(&messages, &signatures, &public_keys).unwrap());
+    }
+
+    #[test]
+    fn batch_verify_fails_on_single_bad_signature() {
+        let messages: Vec<&[u8]> = vec![&MESSAGE1, &[0xff]];
+        let signatures: Vec<&[u8]> = vec![&SIGNATURE1, &SIGNATURE2];
+        let public_keys: Vec<&[u8]> = vec![&PUBLIC_KEY1, &PUBLIC_KEY2];
+
+        assert!(!ed25519_batch_verify(&messages, &signatures, &public_keys).unwrap());
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt::Debug;
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CryptoError {
+    #[error("Hash error: {msg}")]
+    InvalidHashFormat { msg: String },
+    #[error("Signature error: {msg}")]
+    InvalidSignatureFormat { msg: String },
+    #[error("Public key error: {msg}")]
+    InvalidPubkeyFormat { msg: String },
+    #[error("Invalid recovery parameter. Supported values: 0, 1, 2, 3.")]
+    InvalidRecoveryParam {},
+    #[error("Batch error: {msg}")]
+    BatchErr { msg: String },
+    #[error("Generic error: {msg}")]
+    GenericErr { msg: String },
+}
+
+impl CryptoError {
+    pub fn invalid_hash_format() -> Self {
+        CryptoError::InvalidHashFormat {
+            msg: "Hash must be 32 bytes long".into(),
+        }
+    }
+
+    pub fn invalid_signature_format() -> Self {
+        CryptoError::InvalidSignatureFormat {
+            msg: "Signature must be 64 bytes long".into(),
+        }
+    }
+
+    pub fn invalid_signature_format_msg(msg: impl Into<String>) -> Self {
+        CryptoError::InvalidSignatureFormat { msg: msg.into() }
+    }
+
+    pub fn invalid_pubkey_format() -> Self {
+        CryptoError::InvalidPubkeyFormat {
+            msg: "Invalid public key format".into(),
+        }
+    }
+
+    pub fn invalid_recovery_param() -> Self {
+        CryptoError::InvalidRecoveryParam {}
+    }
+
+    pub fn batch_err(msg: impl Into<String>) -> Self {
+        CryptoError::BatchErr { msg: msg.into() }
+    }
+
+    pub fn generic_err(msg: impl Debug) -> Self {
+        CryptoError::GenericErr {
+            msg: format!("{:?}", msg),
+        }
+    }
+}
+
+pub type CryptoResult<T> = core::result::Result<T, CryptoError>;
@@ -0,0 +1,58 @@
+use digest::consts::U32;
+use digest::generic_array::GenericArray;
+use digest::Digest;
+
+/// A digest that just returns the bytes it was given, used to feed an
+/// already-hashed `message_hash` into APIs (like k256's recoverable
+/// signatures) that expect something implementing [`Digest`].
+#[derive(Clone, Default)]
+pub struct Identity256 {
+    hash: GenericArray<u8, U32>,
+}
+
+impl Identity256 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Digest for Identity256 {
+    type OutputSize = U32;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.hash = GenericArray::clone_from_slice(data.as_ref());
+    }
+
+    fn chain(mut self, data: impl AsRef<[u8]>) -> Self {
+        self.update(data);
+        self
+    }
+
+    fn finalize(self) -> GenericArray<u8, Self::OutputSize> {
+        self.hash
+    }
+
+    fn finalize_reset(&mut self) -> GenericArray<u8, Self::OutputSize> {
+        let hash = self.hash;
+        self.reset();
+        hash
+    }
+
+    fn reset(&mut self) {
+        self.hash = Default::default();
+    }
+
+    fn output_size() -> usize {
+        32
+    }
+
+    fn digest(data: &[u8]) -> GenericArray<u8, Self::OutputSize> {
+        let mut hasher = Self::new();
+        hasher.update(data);
+        hasher.finalize()
+    }
+}
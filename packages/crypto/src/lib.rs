@@ -0,0 +1,11 @@
+//! Cryptographic primitives shared by `cosmwasm-std`'s `MockApi` and the
+//! VM's host function implementations, so both sides use the same logic.
+
+mod ed25519;
+mod error;
+mod identity_digest;
+mod secp256k1;
+
+pub use ed25519::{ed25519_batch_verify, ed25519_verify};
+pub use error::{CryptoError, CryptoResult};
+pub use secp256k1::{secp256k1_recover_pubkey, secp256k1_verify};
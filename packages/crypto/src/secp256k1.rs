@@ -0,0 +1,125 @@
+use digest::Digest;
+use k256::ecdsa::recoverable;
+use k256::ecdsa::signature::DigestVerifier;
+use k256::EncodedPoint;
+
+use crate::error::{CryptoError, CryptoResult};
+use crate::identity_digest::Identity256;
+
+/// n / 2 for the secp256k1 curve order. Signatures with `s` above this value
+/// are malleable (an equally valid low-S signature exists) and are rejected.
+const SECP256K1_HALF_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+pub fn secp256k1_verify(
+    message_hash: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+) -> CryptoResult<bool> {
+    let message_hash = read_hash(message_hash)?;
+    let signature = k256::ecdsa::Signature::try_from(signature)
+        .map_err(|_| CryptoError::invalid_signature_format())?;
+    let public_key = k256::ecdsa::VerifyingKey::from_sec1_bytes(public_key)
+        .map_err(|_| CryptoError::invalid_pubkey_format())?;
+
+    let digest = Identity256::new().chain(message_hash);
+    Ok(public_key.verify_digest(digest, &signature).is_ok())
+}
+
+/// Recovers a secp256k1 public key from a 32-byte message hash, a 64-byte
+/// compact signature (`r || s`) and a recovery id in `0..=3`, Ethereum
+/// `ecrecover`-style. The returned key is SEC1-encoded; pass
+/// `compressed = true` for the 33-byte form, `false` for the 65-byte form.
+pub fn secp256k1_recover_pubkey(
+    message_hash: &[u8],
+    signature: &[u8],
+    recovery_param: u8,
+    compressed: bool,
+) -> CryptoResult<Vec<u8>> {
+    let message_hash = read_hash(message_hash)?;
+    let signature = read_signature(signature)?;
+
+    if recovery_param > 3 {
+        return Err(CryptoError::invalid_recovery_param());
+    }
+    if is_malleable(&signature) {
+        return Err(CryptoError::invalid_signature_format_msg(
+            "signature is not normalized (s is in the upper half of the curve order)",
+        ));
+    }
+
+    let id = recoverable::Id::new(recovery_param).map_err(CryptoError::generic_err)?;
+    let normalized = k256::ecdsa::Signature::try_from(&signature[..])
+        .map_err(|_| CryptoError::invalid_signature_format())?;
+    let recoverable_signature =
+        recoverable::Signature::new(&normalized, id).map_err(CryptoError::generic_err)?;
+
+    let digest = Identity256::new().chain(message_hash);
+    let recovered_key = recoverable_signature
+        .recover_verify_key_from_digest(digest)
+        .map_err(CryptoError::generic_err)?;
+
+    let point: EncodedPoint = recovered_key.to_encoded_point(compressed);
+    Ok(point.as_bytes().to_vec())
+}
+
+fn read_hash(data: &[u8]) -> CryptoResult<[u8; 32]> {
+    data.try_into().map_err(|_| CryptoError::invalid_hash_format())
+}
+
+fn read_signature(data: &[u8]) -> CryptoResult<[u8; 64]> {
+    data.try_into().map_err(|_| CryptoError::invalid_signature_format())
+}
+
+fn is_malleable(signature: &[u8; 64]) -> bool {
+    &signature[32..] > &SECP256K1_HALF_ORDER[..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    // From contracts/crypto-verify's `recover_pubkey_works` test vector, derived
+    // independently to confirm the recovery math here matches.
+    const MESSAGE_HASH: [u8; 32] =
+        hex!("6666229ef330ea2b79f4d78138c2f1252e5b11758d74184646cba12b3f9cfb3a");
+    const SIGNATURE: [u8; 64] = hex!("5ad2703f5b4f4b9dea4c28fa30d86d3781d28e09dd51aae1208de80bb6155bee6f15028626e59840ad6d08e1dab3177b005bf41e88cf50fccfb058f46281e3dc");
+    const RECOVERY_PARAM: u8 = 0;
+    const PUBLIC_KEY: [u8; 65] = hex!("04f01d6b9018ab421dd410404cb869072065522bf85734008f105cf385a023a80f0eba29d0f0c5408ed681984dc525982abefccd9f7ff01dd26da4999cf3f6a295");
+
+    #[test]
+    fn recover_pubkey_works() {
+        let recovered =
+            secp256k1_recover_pubkey(&MESSAGE_HASH, &SIGNATURE, RECOVERY_PARAM, false).unwrap();
+        assert_eq!(recovered, PUBLIC_KEY);
+    }
+
+    #[test]
+    fn recover_pubkey_rejects_invalid_recovery_param() {
+        let err = secp256k1_recover_pubkey(&MESSAGE_HASH, &SIGNATURE, 4, false).unwrap_err();
+        assert!(matches!(err, CryptoError::InvalidRecoveryParam {}));
+    }
+
+    #[test]
+    fn recover_pubkey_rejects_wrong_length_input() {
+        let err = secp256k1_recover_pubkey(&[], &SIGNATURE, RECOVERY_PARAM, false).unwrap_err();
+        assert!(matches!(err, CryptoError::InvalidHashFormat { .. }));
+
+        let err = secp256k1_recover_pubkey(&MESSAGE_HASH, &[], RECOVERY_PARAM, false).unwrap_err();
+        assert!(matches!(err, CryptoError::InvalidSignatureFormat { .. }));
+    }
+
+    #[test]
+    fn recover_pubkey_rejects_high_s_signature() {
+        let mut malleable = SIGNATURE;
+        for (b, half) in malleable[32..].iter_mut().zip(SECP256K1_HALF_ORDER.iter()) {
+            *b = half.wrapping_add(1);
+        }
+        let err =
+            secp256k1_recover_pubkey(&MESSAGE_HASH, &malleable, RECOVERY_PARAM, false).unwrap_err();
+        assert!(matches!(err, CryptoError::InvalidSignatureFormat { .. }));
+    }
+}
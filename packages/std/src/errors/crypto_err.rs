@@ -0,0 +1,68 @@
+use thiserror::Error;
+
+use cosmwasm_crypto::CryptoError;
+
+/// An error type for signature verification (`secp256k1_verify`, `ed25519_verify`,
+/// `ed25519_batch_verify`). This is not used in the contract API, as these
+/// functions return plain `bool` and panic on malformed input instead, but it
+/// gives `cosmwasm-vm`'s `BackendApi` implementations a typed error to report
+/// over FFI.
+#[derive(Error, Debug)]
+pub enum VerificationError {
+    #[error("Batch error: {msg}")]
+    BatchErr { msg: String },
+    #[error("Generic error: {msg}")]
+    GenericErr { msg: String },
+    #[error("Invalid hash format")]
+    InvalidHashFormat,
+    #[error("Invalid public key format")]
+    InvalidPubkeyFormat,
+    #[error("Invalid signature format")]
+    InvalidSignatureFormat,
+}
+
+impl From<CryptoError> for VerificationError {
+    fn from(err: CryptoError) -> Self {
+        match err {
+            CryptoError::InvalidHashFormat { .. } => VerificationError::InvalidHashFormat,
+            CryptoError::InvalidPubkeyFormat { .. } => VerificationError::InvalidPubkeyFormat,
+            CryptoError::InvalidSignatureFormat { .. } => VerificationError::InvalidSignatureFormat,
+            CryptoError::InvalidRecoveryParam {} => VerificationError::GenericErr {
+                msg: "invalid recovery param".into(),
+            },
+            CryptoError::BatchErr { msg } => VerificationError::BatchErr { msg },
+            CryptoError::GenericErr { msg } => VerificationError::GenericErr { msg },
+        }
+    }
+}
+
+/// An error type for [`crate::traits::Api::secp256k1_recover_pubkey`]. Unlike
+/// the other crypto methods, recovery surfaces this as a `Result` all the way
+/// up to the contract instead of panicking, since a malformed recovery id or
+/// signature is an expected, recoverable input error.
+#[derive(Error, Debug)]
+pub enum RecoverPubkeyError {
+    #[error("Invalid hash format")]
+    InvalidHashFormat,
+    #[error("Invalid signature format")]
+    InvalidSignatureFormat,
+    #[error("Invalid recovery parameter. Supported values: 0, 1, 2, 3.")]
+    InvalidRecoveryParam,
+    #[error("Unknown error: {msg}")]
+    UnknownErr { msg: String },
+}
+
+impl From<CryptoError> for RecoverPubkeyError {
+    fn from(err: CryptoError) -> Self {
+        match err {
+            CryptoError::InvalidHashFormat { .. } => RecoverPubkeyError::InvalidHashFormat,
+            CryptoError::InvalidSignatureFormat { .. } => {
+                RecoverPubkeyError::InvalidSignatureFormat
+            }
+            CryptoError::InvalidRecoveryParam {} => RecoverPubkeyError::InvalidRecoveryParam,
+            CryptoError::InvalidPubkeyFormat { msg } => RecoverPubkeyError::UnknownErr { msg },
+            CryptoError::BatchErr { msg } => RecoverPubkeyError::UnknownErr { msg },
+            CryptoError::GenericErr { msg } => RecoverPubkeyError::UnknownErr { msg },
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod crypto_err;
+
+pub use crypto_err::{RecoverPubkeyError, VerificationError};
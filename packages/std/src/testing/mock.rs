@@ -0,0 +1,78 @@
+use crate::errors::RecoverPubkeyError;
+use crate::traits::Api;
+
+const MALFORMED_INPUT_MSG: &str = "must be valid inputs (got an empty or malformed argument)";
+
+/// An `Api` implementation backed directly by `cosmwasm-crypto`, used for
+/// unit-testing contracts without a real VM.
+#[derive(Copy, Clone)]
+pub struct MockApi {}
+
+impl Default for MockApi {
+    fn default() -> Self {
+        MockApi {}
+    }
+}
+
+impl Api for MockApi {
+    fn secp256k1_verify(&self, message_hash: &[u8], signature: &[u8], public_key: &[u8]) -> bool {
+        cosmwasm_crypto::secp256k1_verify(message_hash, signature, public_key)
+            .expect(MALFORMED_INPUT_MSG)
+    }
+
+    fn secp256k1_recover_pubkey(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        recovery_param: u8,
+    ) -> Result<Vec<u8>, RecoverPubkeyError> {
+        cosmwasm_crypto::secp256k1_recover_pubkey(message_hash, signature, recovery_param, false)
+            .map_err(Into::into)
+    }
+
+    fn ed25519_verify(&self, message: &[u8], signature: &[u8], public_key: &[u8]) -> bool {
+        cosmwasm_crypto::ed25519_verify(message, signature, public_key).expect(MALFORMED_INPUT_MSG)
+    }
+
+    fn ed25519_batch_verify(
+        &self,
+        messages: &[&[u8]],
+        signatures: &[&[u8]],
+        public_keys: &[&[u8]],
+    ) -> bool {
+        cosmwasm_crypto::ed25519_batch_verify(messages, signatures, public_keys)
+            .expect(MALFORMED_INPUT_MSG)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secp256k1_recover_pubkey_works() {
+        let api = MockApi::default();
+        let message_hash =
+            hex::decode("6666229ef330ea2b79f4d78138c2f1252e5b11758d74184646cba12b3f9cfb3a")
+                .unwrap();
+        let signature = hex::decode("5ad2703f5b4f4b9dea4c28fa30d86d3781d28e09dd51aae1208de80bb6155bee6f15028626e59840ad6d08e1dab3177b005bf41e88cf50fccfb058f46281e3dc").unwrap();
+
+        let pubkey = api.secp256k1_recover_pubkey(&message_hash, &signature, 0).unwrap();
+        assert_eq!(
+            pubkey,
+            hex::decode("04f01d6b9018ab421dd410404cb869072065522bf85734008f105cf385a023a80f0eba29d0f0c5408ed681984dc525982abefccd9f7ff01dd26da4999cf3f6a295").unwrap()
+        );
+    }
+
+    #[test]
+    fn ed25519_verify_works() {
+        let api = MockApi::default();
+        let message: Vec<u8> = vec![];
+        let signature = hex::decode("e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b").unwrap();
+        let public_key =
+            hex::decode("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a")
+                .unwrap();
+
+        assert!(api.ed25519_verify(&message, &signature, &public_key));
+    }
+}
@@ -0,0 +1,3 @@
+mod mock;
+
+pub use mock::MockApi;
@@ -0,0 +1,41 @@
+use crate::errors::RecoverPubkeyError;
+
+/// Access to the VM's crypto host functions. A contract only ever sees this
+/// through `deps.api`; the concrete implementation is either [`MockApi`] in
+/// unit tests or a `cosmwasm-vm` `BackendApi`-backed implementation wired
+/// through imported host functions when running compiled wasm.
+///
+/// [`MockApi`]: crate::testing::MockApi
+pub trait Api: Copy + Clone + Send {
+    /// Verifies a secp256k1 signature over a 32-byte message hash. Panics on
+    /// malformed (e.g. empty or wrong-length) input, matching the historical
+    /// behavior of this method.
+    fn secp256k1_verify(&self, message_hash: &[u8], signature: &[u8], public_key: &[u8]) -> bool;
+
+    /// Recovers a secp256k1 public key from a 32-byte message hash, a 64-byte
+    /// compact signature and a recovery id in `0..=3`, Ethereum
+    /// `ecrecover`-style. Returns the uncompressed, 65-byte SEC1-encoded key.
+    ///
+    /// Unlike [`Api::secp256k1_verify`], malformed input is reported as an
+    /// error rather than a panic.
+    fn secp256k1_recover_pubkey(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        recovery_param: u8,
+    ) -> Result<Vec<u8>, RecoverPubkeyError>;
+
+    /// Verifies an ed25519 signature over the full message (ed25519 signs the
+    /// message itself, not a pre-hash of it). Panics on malformed input.
+    fn ed25519_verify(&self, message: &[u8], signature: &[u8], public_key: &[u8]) -> bool;
+
+    /// Verifies a batch of ed25519 signatures at once; a single failure fails
+    /// the whole batch. `messages`/`public_keys` of length 1 are broadcast to
+    /// the length of the other slices. Panics on malformed input.
+    fn ed25519_batch_verify(
+        &self,
+        messages: &[&[u8]],
+        signatures: &[&[u8]],
+        public_keys: &[&[u8]],
+    ) -> bool;
+}
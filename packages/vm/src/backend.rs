@@ -0,0 +1,62 @@
+use crate::VmResult;
+
+/// Gas accounting for a single backend/host-function call: `cost` is charged
+/// against the contract's remaining gas, `externally_used` is tracked for
+/// metrics but not charged against the limit (e.g. work the embedding chain
+/// already pays for elsewhere).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GasInfo {
+    pub cost: u64,
+    pub externally_used: u64,
+}
+
+impl GasInfo {
+    pub fn with_cost(cost: u64) -> Self {
+        GasInfo {
+            cost,
+            externally_used: 0,
+        }
+    }
+
+    pub fn free() -> Self {
+        GasInfo::default()
+    }
+}
+
+pub type BackendResult<T> = (VmResult<T>, GasInfo);
+
+/// The crypto host functions the VM exposes to compiled contracts, evaluated
+/// outside the wasm sandbox so they can use native crypto crates directly.
+/// `cosmwasm_std::testing::MockApi` implements the equivalent, panic-on-bad-input
+/// contract-facing API on top of the same `cosmwasm-crypto` functions; this
+/// trait instead threads gas cost back to the caller, since these calls are
+/// metered against the contract's gas limit.
+pub trait BackendApi: Copy + Clone + Send {
+    fn secp256k1_verify(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> BackendResult<bool>;
+
+    fn secp256k1_recover_pubkey(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        recovery_param: u8,
+    ) -> BackendResult<Vec<u8>>;
+
+    fn ed25519_verify(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> BackendResult<bool>;
+
+    fn ed25519_batch_verify(
+        &self,
+        messages: &[&[u8]],
+        signatures: &[&[u8]],
+        public_keys: &[&[u8]],
+    ) -> BackendResult<bool>;
+}
@@ -0,0 +1,131 @@
+use wasmer::{Module, Store};
+
+use crate::modules::in_memory_cache::{InMemoryCache, Metrics};
+use crate::{Checksum, Size, VmResult};
+
+/// Configuration for [`Cache::new`].
+pub struct CacheOptions {
+    /// The total weight, in bytes, the in-memory module cache may hold.
+    pub memory_cache_size: Size,
+}
+
+/// The module cache an embedding chain actually talks to. Wraps
+/// [`InMemoryCache`], the in-process tier; a persistent, on-disk tier lives
+/// outside the scope of this crate fragment.
+pub struct Cache {
+    memory_cache: InMemoryCache,
+}
+
+impl Cache {
+    pub fn new(options: CacheOptions) -> Self {
+        Cache {
+            memory_cache: InMemoryCache::new(options.memory_cache_size),
+        }
+    }
+
+    pub fn store(&mut self, checksum: &Checksum, module: Module) -> VmResult<()> {
+        self.memory_cache.store(checksum, module)
+    }
+
+    pub fn load(&mut self, checksum: &Checksum, store: &Store) -> VmResult<Option<Module>> {
+        self.memory_cache.load(checksum, store)
+    }
+
+    /// Pins a module in memory so it is never evicted, for hot contracts an
+    /// operator wants to keep instantiation-ready across every block.
+    pub fn pin(&mut self, checksum: &Checksum, module: Module) {
+        self.memory_cache.pin(checksum, module)
+    }
+
+    /// Unpins a previously pinned module, making it evictable again.
+    pub fn unpin(&mut self, checksum: &Checksum) {
+        self.memory_cache.unpin(checksum)
+    }
+
+    /// Statistics for the pinned tier, for operators sizing the caches.
+    pub fn pinned_metrics(&self) -> Metrics {
+        self.memory_cache.pinned_metrics()
+    }
+
+    /// Statistics for the regular, evictable tier.
+    pub fn metrics(&self) -> Metrics {
+        self.memory_cache.metrics()
+    }
+
+    /// The current aggregate weight (in bytes) of the regular, evictable
+    /// tier, for monitoring how full the cache is relative to its configured size.
+    pub fn size_bytes(&self) -> usize {
+        self.memory_cache.size_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wasm_backend::{compile_only, make_runtime_store};
+
+    const TESTING_MEMORY_LIMIT: Size = Size::mebi(16);
+
+    fn make_cache() -> Cache {
+        Cache::new(CacheOptions {
+            memory_cache_size: Size::mebi(200),
+        })
+    }
+
+    #[test]
+    fn pin_and_unpin_are_reachable_through_cache() {
+        let mut cache = make_cache();
+
+        let wasm = wat::parse_str(
+            r#"(module
+            (type $t0 (func (param i32) (result i32)))
+            (func $add_one (export "add_one") (type $t0) (param $p0 i32) (result i32)
+                get_local $p0
+                i32.const 1
+                i32.add)
+            )"#,
+        )
+        .unwrap();
+        let checksum = Checksum::generate(&wasm);
+        let original = compile_only(&wasm).unwrap();
+
+        cache.pin(&checksum, original);
+        assert_eq!(cache.pinned_metrics().elements, 1);
+        assert_eq!(cache.metrics().elements, 0);
+
+        let store = make_runtime_store(TESTING_MEMORY_LIMIT);
+        assert!(cache.load(&checksum, &store).unwrap().is_some());
+        assert_eq!(cache.pinned_metrics().hits, 1);
+
+        cache.unpin(&checksum);
+        assert_eq!(cache.pinned_metrics().elements, 0);
+
+        let store = make_runtime_store(TESTING_MEMORY_LIMIT);
+        assert!(cache.load(&checksum, &store).unwrap().is_none());
+    }
+
+    #[test]
+    fn store_and_load_are_reachable_through_cache() {
+        let mut cache = make_cache();
+
+        let wasm = wat::parse_str(
+            r#"(module
+            (type $t0 (func (param i32) (result i32)))
+            (func $add_one (export "add_one") (type $t0) (param $p0 i32) (result i32)
+                get_local $p0
+                i32.const 1
+                i32.add)
+            )"#,
+        )
+        .unwrap();
+        let checksum = Checksum::generate(&wasm);
+        let original = compile_only(&wasm).unwrap();
+
+        cache.store(&checksum, original).unwrap();
+        assert_eq!(cache.metrics().elements, 1);
+        assert!(cache.size_bytes() > 0);
+
+        let store = make_runtime_store(TESTING_MEMORY_LIMIT);
+        assert!(cache.load(&checksum, &store).unwrap().is_some());
+    }
+}
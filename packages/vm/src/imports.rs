@@ -0,0 +1,155 @@
+//! Host function entry points backing `deps.api`'s crypto methods for
+//! compiled contracts. These operate on the already-decoded byte slices;
+//! reading the arguments out of the guest's linear memory and writing the
+//! result back is done by the wasm-facing import wrappers that register
+//! these with the wasmer instance, not by the functions below.
+
+use crate::backend::{BackendApi, BackendResult, GasInfo};
+use crate::VmResult;
+
+const GAS_COST_SECP256K1_VERIFY: u64 = 1_750_000;
+const GAS_COST_SECP256K1_RECOVER_PUBKEY: u64 = 1_750_000;
+const GAS_COST_ED25519_VERIFY: u64 = 1_750_000;
+// Batch verification amortizes the expensive scalar/point work across many
+// signatures, so the marginal cost per signature is well below the cost of
+// verifying that many signatures individually.
+const GAS_COST_ED25519_BATCH_VERIFY_BASE: u64 = 1_000_000;
+const GAS_COST_ED25519_BATCH_VERIFY_PER_ITEM: u64 = 100_000;
+
+pub fn do_secp256k1_verify<A: BackendApi>(
+    api: A,
+    message_hash: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+) -> BackendResult<bool> {
+    api.secp256k1_verify(message_hash, signature, public_key)
+}
+
+pub fn do_secp256k1_recover_pubkey<A: BackendApi>(
+    api: A,
+    message_hash: &[u8],
+    signature: &[u8],
+    recovery_param: u8,
+) -> BackendResult<Vec<u8>> {
+    api.secp256k1_recover_pubkey(message_hash, signature, recovery_param)
+}
+
+pub fn do_ed25519_verify<A: BackendApi>(
+    api: A,
+    message: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+) -> BackendResult<bool> {
+    api.ed25519_verify(message, signature, public_key)
+}
+
+pub fn do_ed25519_batch_verify<A: BackendApi>(
+    api: A,
+    messages: &[&[u8]],
+    signatures: &[&[u8]],
+    public_keys: &[&[u8]],
+) -> BackendResult<bool> {
+    api.ed25519_batch_verify(messages, signatures, public_keys)
+}
+
+/// A [`BackendApi`] backed directly by `cosmwasm-crypto`, used to unit-test
+/// the host functions above without a full wasmer `Instance`/`Environment`.
+#[derive(Copy, Clone)]
+pub struct MockBackendApi {}
+
+impl BackendApi for MockBackendApi {
+    fn secp256k1_verify(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> BackendResult<bool> {
+        let result = cosmwasm_crypto::secp256k1_verify(message_hash, signature, public_key)
+            .map_err(|err| crate::VmError::generic_err(err.to_string()));
+        (result as VmResult<bool>, GasInfo::with_cost(GAS_COST_SECP256K1_VERIFY))
+    }
+
+    fn secp256k1_recover_pubkey(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        recovery_param: u8,
+    ) -> BackendResult<Vec<u8>> {
+        let result =
+            cosmwasm_crypto::secp256k1_recover_pubkey(message_hash, signature, recovery_param, false)
+                .map_err(|err| crate::VmError::generic_err(err.to_string()));
+        (
+            result as VmResult<Vec<u8>>,
+            GasInfo::with_cost(GAS_COST_SECP256K1_RECOVER_PUBKEY),
+        )
+    }
+
+    fn ed25519_verify(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> BackendResult<bool> {
+        let result = cosmwasm_crypto::ed25519_verify(message, signature, public_key)
+            .map_err(|err| crate::VmError::generic_err(err.to_string()));
+        (result as VmResult<bool>, GasInfo::with_cost(GAS_COST_ED25519_VERIFY))
+    }
+
+    fn ed25519_batch_verify(
+        &self,
+        messages: &[&[u8]],
+        signatures: &[&[u8]],
+        public_keys: &[&[u8]],
+    ) -> BackendResult<bool> {
+        let cost =
+            GAS_COST_ED25519_BATCH_VERIFY_BASE + GAS_COST_ED25519_BATCH_VERIFY_PER_ITEM * signatures.len() as u64;
+        let result = cosmwasm_crypto::ed25519_batch_verify(messages, signatures, public_keys)
+            .map_err(|err| crate::VmError::generic_err(err.to_string()));
+        (result as VmResult<bool>, GasInfo::with_cost(cost))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGE_HASH_HEX: &str =
+        "6666229ef330ea2b79f4d78138c2f1252e5b11758d74184646cba12b3f9cfb3a";
+    const SIGNATURE_HEX: &str = "5ad2703f5b4f4b9dea4c28fa30d86d3781d28e09dd51aae1208de80bb6155bee6f15028626e59840ad6d08e1dab3177b005bf41e88cf50fccfb058f46281e3dc";
+    const PUBLIC_KEY_HEX: &str = "04f01d6b9018ab421dd410404cb869072065522bf85734008f105cf385a023a80f0eba29d0f0c5408ed681984dc525982abefccd9f7ff01dd26da4999cf3f6a295";
+
+    #[test]
+    fn do_secp256k1_recover_pubkey_works() {
+        let api = MockBackendApi {};
+        let message_hash = hex::decode(MESSAGE_HASH_HEX).unwrap();
+        let signature = hex::decode(SIGNATURE_HEX).unwrap();
+
+        let (result, gas_info) = do_secp256k1_recover_pubkey(api, &message_hash, &signature, 0);
+        assert_eq!(result.unwrap(), hex::decode(PUBLIC_KEY_HEX).unwrap());
+        assert_eq!(gas_info.cost, GAS_COST_SECP256K1_RECOVER_PUBKEY);
+    }
+
+    #[test]
+    fn do_ed25519_batch_verify_cost_scales_sub_linearly() {
+        let api = MockBackendApi {};
+        let message: Vec<u8> = vec![];
+        let signature = hex::decode("e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b").unwrap();
+        let public_key =
+            hex::decode("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a")
+                .unwrap();
+
+        let messages: Vec<&[u8]> = vec![&message];
+        let signatures: Vec<&[u8]> = vec![&signature];
+        let public_keys: Vec<&[u8]> = vec![&public_key];
+        let (_, single_gas) = do_ed25519_batch_verify(api, &messages, &signatures, &public_keys);
+
+        let messages: Vec<&[u8]> = vec![&message; 10];
+        let signatures: Vec<&[u8]> = vec![&signature; 10];
+        let public_keys: Vec<&[u8]> = vec![&public_key; 10];
+        let (result, batch_gas) = do_ed25519_batch_verify(api, &messages, &signatures, &public_keys);
+
+        assert!(result.unwrap());
+        // 10x the signatures costs far less than 10x the single-signature gas.
+        assert!(batch_gas.cost < single_gas.cost * 10);
+    }
+}
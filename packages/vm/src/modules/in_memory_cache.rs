@@ -1,45 +1,163 @@
-use clru::CLruCache;
+use clru::{CLruCache, CLruCacheConfig, WeightScale};
+use std::collections::HashMap;
 use std::sync::Arc;
 use wasmer::{Artifact, Module, Store};
 
-use crate::{Checksum, Size, VmResult};
+use crate::{Checksum, Size, VmError, VmResult};
 
-const ESTIMATED_MODULE_SIZE: Size = Size::mebi(10);
+/// A lower bound for the weight of a single cache entry, so that an
+/// (unrealistic) zero-length artifact can't be inserted an unbounded number
+/// of times without ever exceeding the cache's configured weight limit.
+const MINIMUM_WEIGHT: usize = 16;
 
-/// An in-memory module cache
+type CacheEntry = (Arc<dyn Artifact>, Store);
+
+/// Scales a cache entry's weight to the serialized byte length of its
+/// artifact, so the cache's configured size reflects real memory usage
+/// instead of a fixed per-entry estimate.
+struct ArtifactSizeScale;
+
+impl WeightScale<Checksum, CacheEntry> for ArtifactSizeScale {
+    fn weight(&self, _key: &Checksum, (artifact, _store): &CacheEntry) -> usize {
+        // clru's WeightScale trait has no fallible path. By the time an entry
+        // reaches here, `store` has already computed its weight via
+        // `artifact_weight` and bailed out on a serialization error, so this
+        // fallback is not expected to be exercised in practice.
+        artifact_weight(artifact).unwrap_or(MINIMUM_WEIGHT)
+    }
+}
+
+/// The weight (in bytes) an artifact would occupy in the cache, based on its
+/// real serialized size. Returns an error rather than guessing a near-zero
+/// weight if serialization fails, so a broken artifact can't be silently
+/// under-accounted for.
+fn artifact_weight(artifact: &Arc<dyn Artifact>) -> VmResult<usize> {
+    let serialized_size = artifact
+        .serialize()
+        .map_err(|e| VmError::generic_err(format!("failed to serialize artifact: {}", e)))?
+        .len();
+    Ok(serialized_size.max(MINIMUM_WEIGHT))
+}
+
+/// Cache hit/miss statistics for one tier of [`InMemoryCache`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Metrics {
+    pub hits: u32,
+    pub misses: u32,
+    pub elements: u32,
+}
+
+/// An in-memory module cache, split into two tiers:
+/// - `pinned`, an unbounded, never-evicted map for modules the caller has
+///   explicitly pinned (e.g. hot contracts executed on every block).
+/// - `artifacts`, the regular LRU cache everything else lives in, weighted
+///   by the real serialized size of each artifact rather than a flat estimate.
 pub struct InMemoryCache {
+    pinned: HashMap<Checksum, CacheEntry>,
+    pinned_hits: u32,
+    pinned_misses: u32,
+
     // Keep the original store in cache due to https://github.com/wasmerio/wasmer/issues/1943.
     // We never re-use it.
-    artifacts: CLruCache<Checksum, (Arc<dyn Artifact>, Store)>,
+    artifacts: CLruCache<Checksum, CacheEntry, std::collections::hash_map::RandomState, ArtifactSizeScale>,
+    hits: u32,
+    misses: u32,
 }
 
 impl InMemoryCache {
-    /// Creates a new cache with the given size (in bytes)
+    /// Creates a new cache that holds entries up to a total weight of
+    /// `size` bytes, measured by the serialized size of each artifact.
     pub fn new(size: Size) -> Self {
-        let max_entries = size.0 / ESTIMATED_MODULE_SIZE.0;
         InMemoryCache {
-            artifacts: CLruCache::new(max_entries),
+            pinned: HashMap::new(),
+            pinned_hits: 0,
+            pinned_misses: 0,
+            artifacts: CLruCache::with_config(
+                CLruCacheConfig::new(size.0).with_scale(ArtifactSizeScale),
+            ),
+            hits: 0,
+            misses: 0,
         }
     }
 
+    /// Moves a module into the pinned tier, where it can never be evicted.
+    /// If the module was already present in the regular LRU cache, it is
+    /// removed from there. The pinned tier is unbounded, so this has no
+    /// fallible path.
+    pub fn pin(&mut self, checksum: &Checksum, module: Module) {
+        self.artifacts.pop(checksum);
+        let artifact = Arc::clone(module.artifact());
+        let store = module.store().clone();
+        self.pinned.insert(*checksum, (artifact, store));
+    }
+
+    /// Removes a module from the pinned tier, if present.
+    pub fn unpin(&mut self, checksum: &Checksum) {
+        self.pinned.remove(checksum);
+    }
+
     pub fn store(&mut self, checksum: &Checksum, module: Module) -> VmResult<()> {
         let artifact = Arc::clone(module.artifact());
+        let weight = artifact_weight(&artifact)?;
+        let capacity = self.artifacts.cap().into();
+        if weight > capacity {
+            return Err(VmError::generic_err(format!(
+                "artifact of {} bytes exceeds the module cache's total capacity of {} bytes and cannot be cached",
+                weight, capacity
+            )));
+        }
         let store = module.store().clone();
         self.artifacts.put(*checksum, (artifact, store));
         Ok(())
     }
 
     /// Looks up a module in the cache and takes its artifact and
-    /// creates a new module from store and artifact.
+    /// creates a new module from store and artifact. The pinned tier is
+    /// checked first, then the regular LRU cache.
     pub fn load(&mut self, checksum: &Checksum, store: &Store) -> VmResult<Option<Module>> {
+        if let Some((artifact, _store)) = self.pinned.get(checksum) {
+            self.pinned_hits += 1;
+            let new_module = Module::from_artifact(store, Arc::clone(artifact));
+            return Ok(Some(new_module));
+        }
+        self.pinned_misses += 1;
+
         match self.artifacts.get(checksum) {
             Some((artifact, _store)) => {
+                self.hits += 1;
                 let new_module = Module::from_artifact(store, Arc::clone(artifact));
                 Ok(Some(new_module))
             }
-            None => Ok(None),
+            None => {
+                self.misses += 1;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Statistics for the pinned tier, for operators sizing the caches.
+    pub fn pinned_metrics(&self) -> Metrics {
+        Metrics {
+            hits: self.pinned_hits,
+            misses: self.pinned_misses,
+            elements: self.pinned.len() as u32,
+        }
+    }
+
+    /// Statistics for the regular, evictable tier.
+    pub fn metrics(&self) -> Metrics {
+        Metrics {
+            hits: self.hits,
+            misses: self.misses,
+            elements: self.artifacts.len() as u32,
         }
     }
+
+    /// The current aggregate weight (in bytes) of the regular, evictable
+    /// tier, for monitoring how full the cache is relative to its configured size.
+    pub fn size_bytes(&self) -> usize {
+        self.artifacts.weight()
+    }
 }
 
 #[cfg(test)]
@@ -103,4 +221,167 @@ mod tests {
             assert_eq!(result[0].unwrap_i32(), 43);
         }
     }
+
+    #[test]
+    fn pinned_modules_are_loaded_from_pinned_tier() {
+        let mut cache = InMemoryCache::new(Size::mebi(200));
+
+        let wasm = wat::parse_str(
+            r#"(module
+            (type $t0 (func (param i32) (result i32)))
+            (func $add_one (export "add_one") (type $t0) (param $p0 i32) (result i32)
+                get_local $p0
+                i32.const 1
+                i32.add)
+            )"#,
+        )
+        .unwrap();
+        let checksum = Checksum::generate(&wasm);
+        let original = compile_only(&wasm).unwrap();
+
+        cache.pin(&checksum, original);
+        assert_eq!(cache.pinned_metrics().elements, 1);
+        assert_eq!(cache.metrics().elements, 0);
+
+        let store = make_runtime_store(TESTING_MEMORY_LIMIT);
+        let cached = cache.load(&checksum, &store).unwrap().unwrap();
+        assert_eq!(cache.pinned_metrics().hits, 1);
+        assert_eq!(cache.metrics().hits, 0);
+
+        let instance = WasmerInstance::new(&cached, &imports! {}).unwrap();
+        set_remaining_points(&instance, TESTING_GAS_LIMIT);
+        let add_one = instance.exports.get_function("add_one").unwrap();
+        let result = add_one.call(&[42.into()]).unwrap();
+        assert_eq!(result[0].unwrap_i32(), 43);
+
+        cache.unpin(&checksum);
+        assert_eq!(cache.pinned_metrics().elements, 0);
+
+        let store = make_runtime_store(TESTING_MEMORY_LIMIT);
+        let cache_entry = cache.load(&checksum, &store).unwrap();
+        assert!(cache_entry.is_none());
+    }
+
+    #[test]
+    fn pinning_removes_module_from_regular_tier() {
+        let mut cache = InMemoryCache::new(Size::mebi(200));
+
+        let wasm = wat::parse_str(
+            r#"(module
+            (type $t0 (func (param i32) (result i32)))
+            (func $add_one (export "add_one") (type $t0) (param $p0 i32) (result i32)
+                get_local $p0
+                i32.const 1
+                i32.add)
+            )"#,
+        )
+        .unwrap();
+        let checksum = Checksum::generate(&wasm);
+        let original = compile_only(&wasm).unwrap();
+        cache.store(&checksum, original).unwrap();
+        assert_eq!(cache.metrics().elements, 1);
+
+        let pinned = compile_only(&wasm).unwrap();
+        cache.pin(&checksum, pinned);
+
+        assert_eq!(cache.metrics().elements, 0);
+        assert_eq!(cache.pinned_metrics().elements, 1);
+    }
+
+    #[test]
+    fn size_bytes_reflects_real_artifact_size() {
+        let mut cache = InMemoryCache::new(Size::mebi(200));
+        assert_eq!(cache.size_bytes(), 0);
+
+        let wasm = wat::parse_str(
+            r#"(module
+            (type $t0 (func (param i32) (result i32)))
+            (func $add_one (export "add_one") (type $t0) (param $p0 i32) (result i32)
+                get_local $p0
+                i32.const 1
+                i32.add)
+            )"#,
+        )
+        .unwrap();
+        let checksum = Checksum::generate(&wasm);
+        let original = compile_only(&wasm).unwrap();
+        let weight = artifact_weight(original.artifact()).unwrap();
+        cache.store(&checksum, original).unwrap();
+
+        // The cache's weight is the artifact's serialized size.
+        assert_eq!(cache.size_bytes(), weight);
+        // A tiny module still weighs at least MINIMUM_WEIGHT bytes, never zero.
+        assert!(cache.size_bytes() >= MINIMUM_WEIGHT);
+    }
+
+    #[test]
+    fn store_evicts_by_weight_not_entry_count() {
+        let wasm1 = wat::parse_str(
+            r#"(module
+            (type $t0 (func (param i32) (result i32)))
+            (func $add_one (export "add_one") (type $t0) (param $p0 i32) (result i32)
+                get_local $p0
+                i32.const 1
+                i32.add)
+            )"#,
+        )
+        .unwrap();
+        let checksum1 = Checksum::generate(&wasm1);
+        let artifact1 = compile_only(&wasm1).unwrap();
+        let weight1 = artifact_weight(artifact1.artifact()).unwrap();
+
+        let wasm2 = wat::parse_str(
+            r#"(module
+            (type $t0 (func (param i32) (result i32)))
+            (func $add_two (export "add_two") (type $t0) (param $p0 i32) (result i32)
+                get_local $p0
+                i32.const 2
+                i32.add)
+            )"#,
+        )
+        .unwrap();
+        let checksum2 = Checksum::generate(&wasm2);
+        let artifact2 = compile_only(&wasm2).unwrap();
+        let weight2 = artifact_weight(artifact2.artifact()).unwrap();
+
+        // Sized so each artifact fits on its own, but not both at once -
+        // this proves genuine evict-to-make-room behavior, not just an
+        // implausibly tiny cache that can't hold a single real artifact.
+        let mut cache = InMemoryCache::new(Size(weight1 + weight2 - 1));
+
+        cache.store(&checksum1, artifact1).unwrap();
+        cache.store(&checksum2, artifact2).unwrap();
+
+        // The first module was evicted to make room for the second.
+        let store = make_runtime_store(TESTING_MEMORY_LIMIT);
+        assert!(cache.load(&checksum1, &store).unwrap().is_none());
+        assert!(cache.load(&checksum2, &store).unwrap().is_some());
+    }
+
+    #[test]
+    fn store_rejects_artifact_larger_than_total_capacity() {
+        let wasm = wat::parse_str(
+            r#"(module
+            (type $t0 (func (param i32) (result i32)))
+            (func $add_one (export "add_one") (type $t0) (param $p0 i32) (result i32)
+                get_local $p0
+                i32.const 1
+                i32.add)
+            )"#,
+        )
+        .unwrap();
+        let checksum = Checksum::generate(&wasm);
+        let artifact = compile_only(&wasm).unwrap();
+        let weight = artifact_weight(artifact.artifact()).unwrap();
+
+        // A cache that can never hold even one copy of this artifact must
+        // report an error instead of silently dropping it, per clru's
+        // over-capacity `put` semantics.
+        let mut cache = InMemoryCache::new(Size(weight - 1));
+        let err = cache.store(&checksum, artifact).unwrap_err();
+        assert!(err.to_string().contains("exceeds the module cache's total capacity"));
+
+        let store = make_runtime_store(TESTING_MEMORY_LIMIT);
+        assert!(cache.load(&checksum, &store).unwrap().is_none());
+    }
 }